@@ -0,0 +1,286 @@
+//! Pluggable GPU backends.
+//!
+//! The TUI talks to GPUs only through the [`GpuBackend`] trait, so it stays
+//! backend-agnostic. An NVIDIA implementation wraps the existing `nvml_wrapper`
+//! calls; an AMD implementation is backed by `rocm_smi_lib`. The two are gated
+//! behind the `nvidia` (default) and `rocm` Cargo features, and [`detect`]
+//! picks whichever library initializes successfully at runtime.
+
+use std::collections::HashSet;
+
+// Per-device GPU statistics surfaced to the TUI.
+pub struct GpuStats {
+    pub index: u32,
+    pub utilization: f32,
+    pub memory_used: u64,
+    pub memory_total: u64,
+    pub temperature: u32,
+    // Power draw and the enforced limit, in milliwatts.
+    pub power_usage: u32,
+    pub power_limit: u32,
+    // Current clocks in MHz.
+    pub sm_clock: u32,
+    pub mem_clock: u32,
+    // Whether the card is currently clamping clocks for power/thermal reasons.
+    pub power_throttled: bool,
+    pub thermal_throttled: bool,
+}
+
+// GPU resources attributed to the monitored training job, summed across all
+// devices it is running on.
+pub struct ProcessGpuUsage {
+    pub vram_used: u64,
+    pub sm_util: u32,
+}
+
+/// A source of GPU telemetry. Vendor backends implement the per-device
+/// primitives; [`GpuBackend::stats`] assembles them into [`GpuStats`].
+pub trait GpuBackend {
+    fn device_count(&self) -> u32;
+    fn utilization(&self, index: u32) -> Option<f32>;
+    /// `(used, total)` device memory in bytes.
+    fn memory_info(&self, index: u32) -> Option<(u64, u64)>;
+    fn temperature(&self, index: u32) -> Option<u32>;
+    /// `(usage, limit)` in milliwatts.
+    fn power(&self, index: u32) -> Option<(u32, u32)>;
+
+    /// `(sm, memory)` clocks in MHz. Defaults to unavailable.
+    fn clocks(&self, _index: u32) -> Option<(u32, u32)> {
+        None
+    }
+
+    /// `(power_throttled, thermal_throttled)`. Defaults to not throttled.
+    fn throttle(&self, _index: u32) -> (bool, bool) {
+        (false, false)
+    }
+
+    /// GPU resources used by the given PIDs. Defaults to zero for backends that
+    /// can't expose per-process accounting.
+    fn process_usage(&self, _pids: &HashSet<u32>) -> ProcessGpuUsage {
+        ProcessGpuUsage { vram_used: 0, sm_util: 0 }
+    }
+
+    /// Collect stats for every device, skipping any whose core metrics are
+    /// unavailable.
+    fn stats(&self) -> Vec<GpuStats> {
+        let mut gpus = Vec::new();
+        for index in 0..self.device_count() {
+            let utilization = match self.utilization(index) {
+                Some(utilization) => utilization,
+                None => continue,
+            };
+            let (memory_used, memory_total) = match self.memory_info(index) {
+                Some(memory) => memory,
+                None => continue,
+            };
+            let temperature = self.temperature(index).unwrap_or(0);
+            let (power_usage, power_limit) = self.power(index).unwrap_or((0, 0));
+            let (sm_clock, mem_clock) = self.clocks(index).unwrap_or((0, 0));
+            let (power_throttled, thermal_throttled) = self.throttle(index);
+            gpus.push(GpuStats {
+                index,
+                utilization,
+                memory_used,
+                memory_total,
+                temperature,
+                power_usage,
+                power_limit,
+                sm_clock,
+                mem_clock,
+                power_throttled,
+                thermal_throttled,
+            });
+        }
+        gpus
+    }
+}
+
+/// Probe the available backends in order and return the first that initializes.
+pub fn detect() -> Option<Box<dyn GpuBackend>> {
+    #[cfg(feature = "nvidia")]
+    {
+        if let Some(backend) = nvidia::NvidiaBackend::new() {
+            return Some(Box::new(backend));
+        }
+    }
+    #[cfg(feature = "rocm")]
+    {
+        if let Some(backend) = rocm::RocmBackend::new() {
+            return Some(Box::new(backend));
+        }
+    }
+    None
+}
+
+#[cfg(feature = "nvidia")]
+mod nvidia {
+    use super::{GpuBackend, ProcessGpuUsage};
+    use nvml_wrapper::enum_wrappers::device::{Clock, ClockId, TemperatureSensor};
+    use nvml_wrapper::bitmasks::device::ThrottleReasons;
+    use nvml_wrapper::Nvml;
+    use std::collections::{HashMap, HashSet};
+
+    // NVIDIA backend wrapping an initialized NVML handle.
+    pub struct NvidiaBackend {
+        nvml: Nvml,
+    }
+
+    impl NvidiaBackend {
+        pub fn new() -> Option<Self> {
+            Nvml::init().ok().map(|nvml| Self { nvml })
+        }
+    }
+
+    impl GpuBackend for NvidiaBackend {
+        fn device_count(&self) -> u32 {
+            self.nvml.device_count().unwrap_or(0)
+        }
+
+        fn utilization(&self, index: u32) -> Option<f32> {
+            let device = self.nvml.device_by_index(index).ok()?;
+            device.utilization_rates().ok().map(|u| u.gpu as f32)
+        }
+
+        fn memory_info(&self, index: u32) -> Option<(u64, u64)> {
+            let device = self.nvml.device_by_index(index).ok()?;
+            device.memory_info().ok().map(|m| (m.used, m.total))
+        }
+
+        fn temperature(&self, index: u32) -> Option<u32> {
+            let device = self.nvml.device_by_index(index).ok()?;
+            device.temperature(TemperatureSensor::Gpu).ok()
+        }
+
+        fn power(&self, index: u32) -> Option<(u32, u32)> {
+            let device = self.nvml.device_by_index(index).ok()?;
+            let usage = device.power_usage().ok()?;
+            let limit = device.enforced_power_limit().unwrap_or(0);
+            Some((usage, limit))
+        }
+
+        fn clocks(&self, index: u32) -> Option<(u32, u32)> {
+            let device = self.nvml.device_by_index(index).ok()?;
+            let sm = device.clock(Clock::SM, ClockId::Current).unwrap_or(0);
+            let mem = device.clock(Clock::Memory, ClockId::Current).unwrap_or(0);
+            Some((sm, mem))
+        }
+
+        fn throttle(&self, index: u32) -> (bool, bool) {
+            let reasons = match self
+                .nvml
+                .device_by_index(index)
+                .and_then(|device| device.current_throttle_reasons())
+            {
+                Ok(reasons) => reasons,
+                Err(_) => return (false, false),
+            };
+            let power = reasons
+                .intersects(ThrottleReasons::SW_POWER_CAP | ThrottleReasons::HW_POWER_BRAKE_SLOWDOWN);
+            let thermal = reasons
+                .intersects(ThrottleReasons::SW_THERMAL_SLOWDOWN | ThrottleReasons::HW_THERMAL_SLOWDOWN);
+            (power, thermal)
+        }
+
+        fn process_usage(&self, pids: &HashSet<u32>) -> ProcessGpuUsage {
+            let mut vram_used = 0;
+            // `process_utilization_stats` returns every buffered sample in the
+            // driver's recent window, so keep only the newest per PID on each
+            // device (by timestamp) to avoid summing the same job several times
+            // over, while still summing a PID that runs on several devices.
+            let mut latest: HashMap<(u32, u32), (u64, u32)> = HashMap::new();
+            for index in 0..self.device_count() {
+                let device = match self.nvml.device_by_index(index) {
+                    Ok(device) => device,
+                    Err(_) => continue,
+                };
+                if let Ok(processes) = device.running_compute_processes() {
+                    for info in processes {
+                        if pids.contains(&info.pid) {
+                            if let nvml_wrapper::enums::device::UsedGpuMemory::Used(bytes) = info.used_gpu_memory {
+                                vram_used += bytes;
+                            }
+                        }
+                    }
+                }
+                if let Ok(samples) = device.process_utilization_stats(None) {
+                    for sample in samples {
+                        if !pids.contains(&sample.pid) {
+                            continue;
+                        }
+                        let entry = latest.entry((index, sample.pid)).or_insert((0, 0));
+                        if sample.timestamp >= entry.0 {
+                            *entry = (sample.timestamp, sample.sm_util);
+                        }
+                    }
+                }
+            }
+            let sm_util = latest.values().map(|(_, util)| util).sum();
+            ProcessGpuUsage { vram_used, sm_util }
+        }
+    }
+}
+
+#[cfg(feature = "rocm")]
+mod rocm {
+    use super::GpuBackend;
+    use rocm_smi_lib::{RocmSmi, RsmiTemperatureMetric, RsmiTemperatureType};
+    use std::cell::RefCell;
+
+    // AMD ROCm backend wrapping an initialized rocm_smi handle. The
+    // `rocm_smi_lib` query methods take `&mut self`, so we hold the handle in a
+    // `RefCell` to keep the `GpuBackend` methods `&self`.
+    pub struct RocmBackend {
+        smi: RefCell<RocmSmi>,
+    }
+
+    impl RocmBackend {
+        pub fn new() -> Option<Self> {
+            RocmSmi::init().ok().map(|smi| Self { smi: RefCell::new(smi) })
+        }
+    }
+
+    impl GpuBackend for RocmBackend {
+        fn device_count(&self) -> u32 {
+            self.smi.borrow_mut().get_device_count()
+        }
+
+        fn utilization(&self, index: u32) -> Option<f32> {
+            self.smi
+                .borrow_mut()
+                .get_device_busy_percent(index)
+                .ok()
+                .map(|busy| busy as f32)
+        }
+
+        fn memory_info(&self, index: u32) -> Option<(u64, u64)> {
+            self.smi
+                .borrow_mut()
+                .get_device_memory_data(index)
+                .ok()
+                .map(|data| (data.vram_used, data.vram_total))
+        }
+
+        fn temperature(&self, index: u32) -> Option<u32> {
+            self.smi
+                .borrow_mut()
+                .get_device_temperature_metric(index, RsmiTemperatureType::Edge, RsmiTemperatureMetric::Current)
+                .ok()
+                .map(|temp| temp as u32)
+        }
+
+        fn power(&self, index: u32) -> Option<(u32, u32)> {
+            // rocm_smi reports socket power and cap in microwatts; the trait
+            // contract is milliwatts, matching NVML.
+            self.smi
+                .borrow_mut()
+                .get_device_power_data(index)
+                .ok()
+                .map(|power| {
+                    (
+                        (power.current_power / 1000) as u32,
+                        (power.default_power_cap / 1000) as u32,
+                    )
+                })
+        }
+    }
+}
@@ -12,62 +12,204 @@
 //! MIT License
 
 use std::time::Duration;
-use sysinfo::{System, SystemExt, ProcessExt, CpuExt};
+use sysinfo::{System, SystemExt, ProcessExt, CpuExt, DiskExt, PidExt};
 use clap::{App, Arg};
 use crossterm::{
     execute,
     terminal::{enable_raw_mode, disable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
     cursor,
 };
-use std::io::stdout;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fs::File;
+use std::io::{stdout, BufRead, BufReader, Seek, SeekFrom};
 use regex::Regex;
 use tui::{
     backend::CrosstermBackend,
     layout::{Layout, Constraint, Direction},
-    widgets::{Block, Borders, Paragraph, List, ListItem},
+    symbols,
+    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, List, ListItem, Paragraph, Sparkline},
     style::{Style, Color},
     Terminal,
 };
 use std::env;
 
+mod gpu;
+use gpu::{GpuBackend, GpuStats, ProcessGpuUsage};
+
+// Named capture groups we pull out of each matching log line, in display order.
+const METRIC_NAMES: [&str; 4] = ["step", "loss", "lr", "tokens_per_sec"];
+
+// Default number of samples kept in the time-series history buffers.
+const DEFAULT_HISTORY_WINDOW: usize = 120;
+
+// Bounded time-series history for the resource metrics, so the TUI can draw
+// trends rather than a single instantaneous value.
+struct History {
+    window: usize,
+    cpu: VecDeque<u64>,
+    memory: VecDeque<u64>,
+    gpu_util: HashMap<u32, VecDeque<u64>>,
+    gpu_mem: HashMap<u32, VecDeque<u64>>,
+    gpu_temp: HashMap<u32, VecDeque<u64>>,
+}
+
+impl History {
+    fn new(window: usize) -> Self {
+        Self {
+            window,
+            cpu: VecDeque::new(),
+            memory: VecDeque::new(),
+            gpu_util: HashMap::new(),
+            gpu_mem: HashMap::new(),
+            gpu_temp: HashMap::new(),
+        }
+    }
+
+    // Push a sample into a buffer, dropping the oldest once the window is full.
+    fn push(buffer: &mut VecDeque<u64>, window: usize, value: u64) {
+        if buffer.len() == window {
+            buffer.pop_front();
+        }
+        buffer.push_back(value);
+    }
+
+    // Record one snapshot of CPU, system memory and per-device GPU stats.
+    fn record(&mut self, cpu: u64, memory: u64, gpus: &[GpuStats]) {
+        Self::push(&mut self.cpu, self.window, cpu);
+        Self::push(&mut self.memory, self.window, memory);
+        for gpu in gpus {
+            Self::push(self.gpu_util.entry(gpu.index).or_default(), self.window, gpu.utilization as u64);
+            Self::push(self.gpu_mem.entry(gpu.index).or_default(), self.window, gpu.memory_used / 1024 / 1024);
+            Self::push(self.gpu_temp.entry(gpu.index).or_default(), self.window, gpu.temperature as u64);
+        }
+    }
+}
+
+// A mounted volume and the space it reports.
+struct DiskInfo {
+    name: String,
+    available: u64,
+    total: u64,
+    // True for the volume backing the log/checkpoint path.
+    holds_log: bool,
+}
+
 // Struct to hold the monitor's state
 struct LLMTrainMonitor {
     system: System,
-    nvml: Option<nvml_wrapper::Nvml>,
+    gpu: Option<Box<dyn GpuBackend>>,
     process_name: String,
     update_interval: Duration,
     log_file_path: Option<String>,
-    metric_regex: Option<Regex>,
+    // `None` means no filter was supplied; `Some(Err(..))` keeps the compile
+    // error around so we can show it in the panel instead of panicking.
+    metric_regex: Option<Result<Regex, regex::Error>>,
+    // Byte offset we've already consumed from the log file so each update only
+    // scans newly-appended lines.
+    log_offset: u64,
+    // Ring buffer of recently parsed values, keyed by capture-group name.
+    metrics: HashMap<String, VecDeque<f64>>,
+    // Bounded time-series history for CPU, memory and GPU metrics.
+    history: History,
 }
 
 impl LLMTrainMonitor {
     // Initialize a new LLMTrainMonitor
-    fn new(process_name: String, update_interval: Duration, log_file_path: Option<String>, metric_regex: Option<String>) -> Self {
+    fn new(process_name: String, update_interval: Duration, log_file_path: Option<String>, metric_regex: Option<String>, history_window: usize) -> Self {
         Self {
             system: System::new_all(),
-            nvml: nvml_wrapper::Nvml::init().ok(),
+            gpu: gpu::detect(),
             process_name,
             update_interval,
             log_file_path,
-            metric_regex: metric_regex.map(|r| Regex::new(&r).expect("Invalid regex pattern")),
+            // A blank pattern means "no filter"; anything else is compiled now
+            // but kept as a `Result` so an invalid pattern surfaces in the TUI.
+            metric_regex: metric_regex
+                .filter(|r| !r.trim().is_empty())
+                .map(|r| Regex::new(&r)),
+            log_offset: 0,
+            metrics: HashMap::new(),
+            history: History::new(history_window),
         }
     }
 
     // Update system and GPU information
     fn update(&mut self) {
         self.system.refresh_all();
+        self.update_log_metrics();
+
+        // Snapshot the resource metrics into the time-series history.
+        let cpu = self.system.global_cpu_info().cpu_usage() as u64;
+        let memory = self.system.used_memory() / 1024 / 1024;
+        let gpus = self.get_gpu_info();
+        self.history.record(cpu, memory, &gpus);
     }
 
-    // Get GPU information
-    fn get_gpu_info(&self) -> Option<(f32, u64, u64, u32)> {
-        self.nvml.as_ref().and_then(|nvml| {
-            nvml.device_by_index(0).ok().and_then(|device| {
-                let utilization = device.utilization_rates().ok()?;
-                let memory = device.memory_info().ok()?;
-                let temp = device.temperature(nvml_wrapper::enum_wrappers::device::TemperatureSensor::Gpu).ok()?;
-                Some((utilization.gpu as f32, memory.used, memory.total, temp))
-            })
-        })
+    // Tail the log file, running `metric_regex` over any newly-appended lines
+    // and pushing parsed named captures into the per-metric ring buffers.
+    fn update_log_metrics(&mut self) {
+        let path = match self.log_file_path.as_ref() {
+            Some(p) => p.clone(),
+            None => return,
+        };
+        let regex = match self.metric_regex.as_ref() {
+            Some(Ok(re)) => re.clone(),
+            _ => return,
+        };
+
+        let mut file = match File::open(&path) {
+            Ok(f) => f,
+            Err(_) => return,
+        };
+        // If the file shrank (e.g. rotated or truncated) start over from the top.
+        let len = file.metadata().map(|m| m.len()).unwrap_or(0);
+        if len < self.log_offset {
+            self.log_offset = 0;
+        }
+        if file.seek(SeekFrom::Start(self.log_offset)).is_err() {
+            return;
+        }
+
+        let mut reader = BufReader::new(file);
+        let mut line = String::new();
+        let mut parsed: Vec<(&'static str, f64)> = Vec::new();
+        loop {
+            line.clear();
+            let read = match reader.read_line(&mut line) {
+                Ok(0) => break,
+                Ok(n) => n,
+                Err(_) => break,
+            };
+            // Only consume complete lines so a half-written tail is re-read next time.
+            if !line.ends_with('\n') {
+                break;
+            }
+            self.log_offset += read as u64;
+
+            for caps in regex.captures_iter(&line) {
+                for name in METRIC_NAMES {
+                    if let Some(value) = caps.name(name).and_then(|m| m.as_str().parse::<f64>().ok()) {
+                        parsed.push((name, value));
+                    }
+                }
+            }
+        }
+
+        // Log metrics share the configurable history window so a multi-hour
+        // loss curve isn't silently truncated.
+        let window = self.history.window;
+        for (name, value) in parsed {
+            let buffer = self.metrics.entry(name.to_string()).or_default();
+            if buffer.len() == window {
+                buffer.pop_front();
+            }
+            buffer.push_back(value);
+        }
+    }
+
+    // Get GPU information for every device the active backend reports.
+    fn get_gpu_info(&self) -> Vec<GpuStats> {
+        self.gpu.as_ref().map(|backend| backend.stats()).unwrap_or_default()
     }
 
     // Get process information
@@ -76,6 +218,84 @@ impl LLMTrainMonitor {
             (process.cpu_usage(), process.memory())
         })
     }
+
+    // Enumerate mounted volumes, flagging the one that holds the log/checkpoint
+    // path so dataset and checkpoint I/O can be attributed to a device.
+    fn get_disk_info(&self) -> Vec<DiskInfo> {
+        // Longest matching mount point wins for the highlighted volume.
+        let mut highlight_len = 0;
+        let mut highlighted = None;
+        if let Some(path) = self.log_file_path.as_ref() {
+            for disk in self.system.disks() {
+                let mount = disk.mount_point().to_string_lossy().to_string();
+                if path.starts_with(&mount) && mount.len() >= highlight_len {
+                    highlight_len = mount.len();
+                    highlighted = Some(mount);
+                }
+            }
+        }
+
+        self.system
+            .disks()
+            .iter()
+            .map(|disk| {
+                let mount = disk.mount_point().to_string_lossy().to_string();
+                DiskInfo {
+                    name: disk.name().to_string_lossy().to_string(),
+                    available: disk.available_space(),
+                    total: disk.total_space(),
+                    holds_log: highlighted.as_deref() == Some(mount.as_str()),
+                }
+            })
+            .collect()
+    }
+
+    // Read/write bytes the monitored process moved over the last update
+    // interval, summed across matching processes (sysinfo's per-process
+    // DiskUsage is the portable proxy for volume I/O rate).
+    fn get_disk_io(&self) -> (u64, u64) {
+        self.system
+            .processes_by_exact_name(&self.process_name)
+            .fold((0, 0), |(read, written), process| {
+                let usage = process.disk_usage();
+                (read + usage.read_bytes, written + usage.written_bytes)
+            })
+    }
+
+    // PIDs belonging to the monitored process and its descendants, so GPU
+    // accounting covers worker/child processes spawned by the trainer too.
+    fn monitored_pids(&self) -> HashSet<u32> {
+        let mut pids: HashSet<u32> = self
+            .system
+            .processes_by_exact_name(&self.process_name)
+            .map(|process| process.pid().as_u32())
+            .collect();
+        // Repeatedly fold in children of known PIDs until the set settles.
+        loop {
+            let mut added = false;
+            for process in self.system.processes().values() {
+                if let Some(parent) = process.parent() {
+                    if pids.contains(&parent.as_u32()) && pids.insert(process.pid().as_u32()) {
+                        added = true;
+                    }
+                }
+            }
+            if !added {
+                break;
+            }
+        }
+        pids
+    }
+
+    // Attribute GPU VRAM and SM utilization to the monitored job by matching
+    // the backend's running-compute-process entries against `monitored_pids()`.
+    fn get_process_gpu_usage(&self) -> ProcessGpuUsage {
+        let pids = self.monitored_pids();
+        self.gpu
+            .as_ref()
+            .map(|backend| backend.process_usage(&pids))
+            .unwrap_or(ProcessGpuUsage { vram_used: 0, sm_util: 0 })
+    }
     // Main loop to continuously update and display information
     fn run(&mut self) -> std::io::Result<()> {
         enable_raw_mode()?;
@@ -88,9 +308,13 @@ impl LLMTrainMonitor {
         let hostname = std::env::var("HOSTNAME").unwrap_or_else(|_| "Unknown".to_string());
         let os_info = format!("{} {}", self.system.name().unwrap_or_default(), self.system.os_version().unwrap_or_default());
         
-        let gpu_info = self.get_gpu_info();
-        let gpu_summary = match &gpu_info {
-            Some((_, _, gpu_memory_total, _)) => format!("GPU: {} MB", gpu_memory_total / 1024 / 1024),
+        let initial_gpus = self.get_gpu_info();
+        let gpu_summary = match initial_gpus.first() {
+            Some(first) => format!(
+                "GPUs: {} | {} MB each",
+                initial_gpus.len(),
+                first.memory_total / 1024 / 1024
+            ),
             None => "No GPU detected".to_string(),
         };
 
@@ -116,8 +340,10 @@ impl LLMTrainMonitor {
                     .margin(1)
                     .constraints([
                         Constraint::Length(5),
-                        Constraint::Length(3),
-                        Constraint::Length(3),
+                        Constraint::Length(6),
+                        Constraint::Length(6),
+                        Constraint::Length(9),
+                        Constraint::Length(6),
                         Constraint::Min(0),
                     ].as_ref())
                     .split(f.size());
@@ -130,39 +356,245 @@ impl LLMTrainMonitor {
                 frame_toggle = !frame_toggle;
 
                 let cpu_usage = self.system.global_cpu_info().cpu_usage();
-                let cpu_info = Paragraph::new(format!("CPU Usage: {:.2}%", cpu_usage))
-                    .block(Block::default().title("CPU Info").borders(Borders::ALL));
+                let cpu_data: Vec<u64> = self.history.cpu.iter().copied().collect();
+                let cpu_info = Sparkline::default()
+                    .block(Block::default().title(format!("CPU Usage: {:.2}%", cpu_usage)).borders(Borders::ALL))
+                    .data(&cpu_data)
+                    .max(100)
+                    .style(Style::default().fg(Color::Green));
                 f.render_widget(cpu_info, chunks[1]);
 
-                let memory_info = Paragraph::new(format!(
-                    "Memory Usage: {} / {} MB",
-                    self.system.used_memory() / 1024 / 1024,
-                    self.system.total_memory() / 1024 / 1024
-                ))
-                .block(Block::default().title("System Memory").borders(Borders::ALL));
+                let memory_data: Vec<u64> = self.history.memory.iter().copied().collect();
+                let memory_info = Sparkline::default()
+                    .block(
+                        Block::default()
+                            .title(format!(
+                                "System Memory: {} / {} MB",
+                                self.system.used_memory() / 1024 / 1024,
+                                self.system.total_memory() / 1024 / 1024
+                            ))
+                            .borders(Borders::ALL),
+                    )
+                    .data(&memory_data)
+                    .style(Style::default().fg(Color::Green));
                 f.render_widget(memory_info, chunks[2]);
 
+                let metrics_block = Block::default().title("Training Metrics").borders(Borders::ALL);
+                match &self.metric_regex {
+                    Some(Err(err)) => {
+                        let invalid = Paragraph::new(format!("invalid regex: {}", err)).block(metrics_block);
+                        f.render_widget(invalid, chunks[3]);
+                    }
+                    _ => {
+                        // Latest values on the left, a loss curve on the right.
+                        let metrics_area = Layout::default()
+                            .direction(Direction::Horizontal)
+                            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+                            .split(chunks[3]);
+
+                        let mut items: Vec<ListItem> = Vec::new();
+                        for name in METRIC_NAMES {
+                            if let Some(buffer) = self.metrics.get(name) {
+                                if let Some(latest) = buffer.back() {
+                                    let recent: Vec<String> = buffer
+                                        .iter()
+                                        .rev()
+                                        .take(5)
+                                        .rev()
+                                        .map(|v| format!("{:.4}", v))
+                                        .collect();
+                                    items.push(ListItem::new(format!(
+                                        "{}: {:.4}  [{}]",
+                                        name,
+                                        latest,
+                                        recent.join(", ")
+                                    )));
+                                }
+                            }
+                        }
+                        if items.is_empty() {
+                            items.push(ListItem::new("Waiting for log metrics..."));
+                        }
+                        let metrics_list = List::new(items).block(metrics_block);
+                        f.render_widget(metrics_list, metrics_area[0]);
+
+                        // Loss curve over the collected history.
+                        let loss_block = Block::default().title("Loss").borders(Borders::ALL);
+                        let loss_points: Vec<(f64, f64)> = self
+                            .metrics
+                            .get("loss")
+                            .map(|buffer| {
+                                buffer
+                                    .iter()
+                                    .enumerate()
+                                    .map(|(i, value)| (i as f64, *value))
+                                    .collect()
+                            })
+                            .unwrap_or_default();
+                        if loss_points.len() < 2 {
+                            let waiting = Paragraph::new("Waiting for loss...").block(loss_block);
+                            f.render_widget(waiting, metrics_area[1]);
+                        } else {
+                            let x_max = (loss_points.len() - 1) as f64;
+                            let y_min = loss_points.iter().map(|(_, y)| *y).fold(f64::INFINITY, f64::min);
+                            let y_max = loss_points.iter().map(|(_, y)| *y).fold(f64::NEG_INFINITY, f64::max);
+                            let dataset = Dataset::default()
+                                .name("loss")
+                                .marker(symbols::Marker::Braille)
+                                .graph_type(GraphType::Line)
+                                .style(Style::default().fg(Color::Cyan))
+                                .data(&loss_points);
+                            let chart = Chart::new(vec![dataset])
+                                .block(loss_block)
+                                .x_axis(Axis::default().bounds([0.0, x_max]))
+                                .y_axis(Axis::default().bounds([y_min, y_max]));
+                            f.render_widget(chart, metrics_area[1]);
+                        }
+                    }
+                }
+
+                // Disk panel: mounts with free/total space and the monitored
+                // job's read/write rate over the last interval.
+                let (read_bytes, written_bytes) = self.get_disk_io();
+                let secs = self.update_interval.as_secs().max(1);
+                let mut disk_items: Vec<ListItem> = self
+                    .get_disk_info()
+                    .iter()
+                    .map(|disk| {
+                        let marker = if disk.holds_log { "* " } else { "  " };
+                        ListItem::new(format!(
+                            "{}{}: {} / {} MB free",
+                            marker,
+                            disk.name,
+                            disk.available / 1024 / 1024,
+                            disk.total / 1024 / 1024
+                        ))
+                    })
+                    .collect();
+                // Compute in floating KB/s so slow dataset/checkpoint streams
+                // (well under 1 MB/s) don't floor to zero and hide stalls.
+                let read_kbps = read_bytes as f64 / 1024.0 / secs as f64;
+                let written_kbps = written_bytes as f64 / 1024.0 / secs as f64;
+                disk_items.push(ListItem::new(format!(
+                    "I/O: read {:.1} KB/s, write {:.1} KB/s",
+                    read_kbps, written_kbps
+                )));
+                let disk_panel = List::new(disk_items)
+                    .block(Block::default().title("Disk I/O").borders(Borders::ALL));
+                f.render_widget(disk_panel, chunks[4]);
+
+                // Split the body into a process panel on the left and one GPU
+                // block per device on the right.
+                let body = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Percentage(40), Constraint::Percentage(60)].as_ref())
+                    .split(chunks[5]);
+
+                let gpus = self.get_gpu_info();
+
                 if let Some((process_cpu_usage, process_memory)) = self.get_process_info() {
+                    let gpu_usage = self.get_process_gpu_usage();
+                    let gpu_memory_total: u64 = gpus.iter().map(|gpu| gpu.memory_total).sum();
                     let process_info = List::new(vec![
                         ListItem::new(format!("CPU Usage: {:.2}%", process_cpu_usage)),
                         ListItem::new(format!("Memory Usage: {} MB", process_memory / 1024 / 1024)),
+                        ListItem::new(format!(
+                            "GPU VRAM: {} / {} MB",
+                            gpu_usage.vram_used / 1024 / 1024,
+                            gpu_memory_total / 1024 / 1024
+                        )),
+                        ListItem::new(format!("GPU SM: {}%", gpu_usage.sm_util)),
                     ])
                     .block(Block::default().title(format!("Process: {}", self.process_name)).borders(Borders::ALL));
-                    f.render_widget(process_info, chunks[3]);
+                    f.render_widget(process_info, body[0]);
                 } else {
                     let no_process_info = Paragraph::new(format!("Process '{}' not found", self.process_name))
                         .block(Block::default().title("Process Info").borders(Borders::ALL));
-                    f.render_widget(no_process_info, chunks[3]);
+                    f.render_widget(no_process_info, body[0]);
                 }
 
-                if let Some((gpu_usage, gpu_memory_used, gpu_memory_total, gpu_temp)) = gpu_info {
-                    let gpu_info = List::new(vec![
-                        ListItem::new(format!("GPU Usage: {:.2}%", gpu_usage)),
-                        ListItem::new(format!("Memory: {} / {} MB", gpu_memory_used / 1024 / 1024, gpu_memory_total / 1024 / 1024)),
-                        ListItem::new(format!("Temperature: {}°C", gpu_temp)),
-                    ])
-                    .block(Block::default().title("GPU Info").borders(Borders::ALL));
-                    f.render_widget(gpu_info, chunks[3]);
+                if gpus.is_empty() {
+                    let no_gpu = Paragraph::new("No GPU detected")
+                        .block(Block::default().title("GPU Info").borders(Borders::ALL));
+                    f.render_widget(no_gpu, body[1]);
+                } else {
+                    // One evenly-sized block per device, resized to the count.
+                    let gpu_constraints: Vec<Constraint> = gpus
+                        .iter()
+                        .map(|_| Constraint::Ratio(1, gpus.len() as u32))
+                        .collect();
+                    let gpu_areas = Layout::default()
+                        .direction(Direction::Vertical)
+                        .constraints(gpu_constraints.as_slice())
+                        .split(body[1]);
+                    for (gpu, area) in gpus.iter().zip(gpu_areas.iter()) {
+                        let throttle = match (gpu.power_throttled, gpu.thermal_throttled) {
+                            (true, true) => " [power+thermal throttled]",
+                            (true, false) => " [power throttled]",
+                            (false, true) => " [thermal throttled]",
+                            (false, false) => "",
+                        };
+                        // Stats list on top, utilization and memory traces underneath.
+                        let gpu_split = Layout::default()
+                            .direction(Direction::Vertical)
+                            .constraints([Constraint::Min(6), Constraint::Length(3)].as_ref())
+                            .split(*area);
+
+                        let gpu_widget = List::new(vec![
+                            ListItem::new(format!("Usage: {:.2}%", gpu.utilization)),
+                            ListItem::new(format!(
+                                "Memory: {} / {} MB",
+                                gpu.memory_used / 1024 / 1024,
+                                gpu.memory_total / 1024 / 1024
+                            )),
+                            ListItem::new(format!("Temperature: {}°C", gpu.temperature)),
+                            ListItem::new(format!(
+                                "Power: {} / {} W{}",
+                                gpu.power_usage / 1000,
+                                gpu.power_limit / 1000,
+                                throttle
+                            )),
+                            ListItem::new(format!("Clocks: SM {} MHz, Mem {} MHz", gpu.sm_clock, gpu.mem_clock)),
+                        ])
+                        .block(Block::default().title(format!("GPU {}", gpu.index)).borders(Borders::ALL));
+                        f.render_widget(gpu_widget, gpu_split[0]);
+
+                        let traces = Layout::default()
+                            .direction(Direction::Horizontal)
+                            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+                            .split(gpu_split[1]);
+
+                        let util_data: Vec<u64> = self
+                            .history
+                            .gpu_util
+                            .get(&gpu.index)
+                            .map(|buffer| buffer.iter().copied().collect())
+                            .unwrap_or_default();
+                        let util_trace = Sparkline::default()
+                            .block(Block::default().title("Util %").borders(Borders::ALL))
+                            .data(&util_data)
+                            .max(100)
+                            .style(Style::default().fg(Color::Green));
+                        f.render_widget(util_trace, traces[0]);
+
+                        let mem_data: Vec<u64> = self
+                            .history
+                            .gpu_mem
+                            .get(&gpu.index)
+                            .map(|buffer| buffer.iter().copied().collect())
+                            .unwrap_or_default();
+                        let peak_temp = self
+                            .history
+                            .gpu_temp
+                            .get(&gpu.index)
+                            .and_then(|buffer| buffer.iter().max().copied())
+                            .unwrap_or(0);
+                        let mem_trace = Sparkline::default()
+                            .block(Block::default().title(format!("Mem MB (peak {}°C)", peak_temp)).borders(Borders::ALL))
+                            .data(&mem_data)
+                            .style(Style::default().fg(Color::Green));
+                        f.render_widget(mem_trace, traces[1]);
+                    }
                 }
             })?;
 
@@ -197,21 +629,29 @@ fn main() -> std::io::Result<()> {
             .required(true)
             .index(2))
         .arg(Arg::with_name("log_file_path")
-            .help("Path to the log file to monitor (under development)")
+            .help("Path to the training log file to tail for metrics")
             .required(false)
             .index(3))
         .arg(Arg::with_name("metric_regex")
-            .help("Regex to extract metrics from log file (under development)")
+            .help("Regex with named groups (loss, lr, step, tokens_per_sec) to extract metrics")
             .required(false)
             .index(4))
+        .arg(Arg::with_name("history_window")
+            .help("Number of samples kept for the history charts")
+            .required(false)
+            .index(5))
         .get_matches();
 
     let process_name = matches.value_of("process_name").unwrap().to_string();
     let update_interval = Duration::from_secs(matches.value_of("update_interval").unwrap().parse().unwrap());
     let log_file_path = matches.value_of("log_file_path").map(String::from);
     let metric_regex = matches.value_of("metric_regex").map(String::from);
+    let history_window = matches
+        .value_of("history_window")
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(DEFAULT_HISTORY_WINDOW);
 
     // Create and run the monitor
-    let mut monitor = LLMTrainMonitor::new(process_name, update_interval, log_file_path, metric_regex);
+    let mut monitor = LLMTrainMonitor::new(process_name, update_interval, log_file_path, metric_regex, history_window);
     monitor.run()
 }
\ No newline at end of file